@@ -25,44 +25,128 @@
 
 use std::sync::Arc;
 
-use arrow::datatypes::{DataType, Field, Schema};
+use arrow::array::{Int64Array, RecordBatch, StringArray};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use bytes::Bytes;
 use datafusion::assert_batches_eq;
 use datafusion::common::Result;
-use datafusion::prelude::{CsvReadOptions, SessionContext};
+use datafusion::prelude::{
+    CsvReadOptions, NdJsonReadOptions, ParquetReadOptions, SessionContext,
+};
 use object_store::memory::InMemory;
 use object_store::path::Path;
 use object_store::{ObjectStore, PutPayload};
+use parquet::arrow::ArrowWriter;
 use url::Url;
 
-/// Demonstrates reading CSV data from an in-memory object store.
+/// Which reader [`register_in_memory_files`] should dispatch to for a given
+/// payload.
+#[derive(Debug, Clone, Copy)]
+pub enum FileFormat {
+    Csv,
+    Json,
+    Parquet,
+}
+
+/// Registers `files` under a single in-memory `ObjectStore` mounted at
+/// `mem://{prefix}/`, writes each payload into it via [`PutPayload`], and
+/// registers each as a queryable table (named after the file's stem),
+/// dispatching to `register_csv`/`register_json`/`register_parquet` based on
+/// its declared [`FileFormat`].
 ///
-/// The same pattern applies to JSON/Parquet: register a store for a URL
-/// prefix, write bytes into the store, then read via that URL.
-pub async fn in_memory_object_store() -> Result<()> {
+/// This gives tests and embedders a one-call path to an entirely in-memory
+/// DataFusion catalog spanning CSV, NDJSON, and Parquet, instead of
+/// hand-writing the store/put/read dance per file and format.
+pub async fn register_in_memory_files(
+    ctx: &SessionContext,
+    prefix: &str,
+    files: &[(Path, Bytes, FileFormat)],
+) -> Result<()> {
     let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
-    let ctx = SessionContext::new();
-    let object_store_url = Url::parse("mem://").unwrap();
-    // Register a URL prefix to route reads through this object store.
+    let object_store_url = Url::parse(&format!("mem://{prefix}/")).unwrap();
     ctx.register_object_store(&object_store_url, Arc::clone(&store));
 
-    let schema = Schema::new(vec![
+    for (path, bytes, format) in files {
+        store.put(path, PutPayload::from_bytes(bytes.clone())).await?;
+
+        let table_name = path
+            .filename()
+            .and_then(|name| name.split('.').next())
+            .filter(|stem| !stem.is_empty())
+            .unwrap_or(path.as_ref());
+        let table_url = format!("mem://{prefix}/{path}");
+
+        match format {
+            FileFormat::Csv => {
+                ctx.register_csv(table_name, &table_url, CsvReadOptions::new())
+                    .await?
+            }
+            FileFormat::Json => {
+                ctx.register_json(table_name, &table_url, NdJsonReadOptions::default())
+                    .await?
+            }
+            FileFormat::Parquet => {
+                ctx.register_parquet(
+                    table_name,
+                    &table_url,
+                    ParquetReadOptions::default(),
+                )
+                .await?
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Encodes `batch` as Parquet bytes, for seeding the in-memory store in this
+/// example without touching the local filesystem.
+fn parquet_bytes(schema: SchemaRef, batch: RecordBatch) -> Bytes {
+    let mut buffer = Vec::new();
+    let mut writer = ArrowWriter::try_new(&mut buffer, schema, None)
+        .expect("failed to create parquet writer");
+    writer.write(&batch).expect("failed to write parquet batch");
+    writer.close().expect("failed to close parquet writer");
+    Bytes::from(buffer)
+}
+
+/// Demonstrates registering CSV, NDJSON, and Parquet data that all live in
+/// the same in-memory object store, via [`register_in_memory_files`].
+pub async fn in_memory_object_store() -> Result<()> {
+    let ctx = SessionContext::new();
+    let schema = Arc::new(Schema::new(vec![
         Field::new("id", DataType::Int64, false),
         Field::new("name", DataType::Utf8, false),
-    ]);
-
-    println!("=== CSV from memory ===");
-    let csv_path = Path::from("/people.csv");
-    let csv_data = b"id,name\n1,Alice\n2,Bob\n";
-    // Write bytes into the in-memory object store.
-    store
-        .put(&csv_path, PutPayload::from_static(csv_data))
-        .await?;
-    // Read using the URL that matches the registered prefix.
-    let csv = ctx
-        .read_csv("mem:///people.csv", CsvReadOptions::new().schema(&schema))
-        .await?
-        .collect()
-        .await?;
+    ]));
+
+    let ids = Arc::new(Int64Array::from(vec![1, 2]));
+    let names = Arc::new(StringArray::from(vec!["Alice", "Bob"]));
+    let batch =
+        RecordBatch::try_new(Arc::clone(&schema), vec![ids, names]).unwrap();
+
+    // Each file gets a distinct stem, since `register_in_memory_files` names
+    // the table after it (e.g. "people_csv.csv" -> table "people_csv").
+    let files = vec![
+        (
+            Path::from("people_csv.csv"),
+            Bytes::from_static(b"id,name\n1,Alice\n2,Bob\n"),
+            FileFormat::Csv,
+        ),
+        (
+            Path::from("people_json.json"),
+            Bytes::from_static(
+                b"{\"id\": 1, \"name\": \"Alice\"}\n{\"id\": 2, \"name\": \"Bob\"}\n",
+            ),
+            FileFormat::Json,
+        ),
+        (
+            Path::from("people_parquet.parquet"),
+            parquet_bytes(Arc::clone(&schema), batch),
+            FileFormat::Parquet,
+        ),
+    ];
+    register_in_memory_files(&ctx, "people", &files).await?;
+
     #[rustfmt::skip]
     let expected = [
         "+----+-------+",
@@ -72,7 +156,16 @@ pub async fn in_memory_object_store() -> Result<()> {
         "| 2  | Bob   |",
         "+----+-------+",
     ];
-    assert_batches_eq!(expected, &csv);
+
+    for table in ["people_csv", "people_json", "people_parquet"] {
+        println!("=== {table} from memory ===");
+        let result = ctx
+            .sql(&format!("SELECT * FROM {table} ORDER BY id"))
+            .await?
+            .collect()
+            .await?;
+        assert_batches_eq!(expected, &result);
+    }
 
     Ok(())
 }
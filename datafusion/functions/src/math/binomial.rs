@@ -0,0 +1,200 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, Int64Array};
+use arrow::datatypes::DataType;
+use arrow::datatypes::DataType::Int64;
+use arrow::error::ArrowError;
+
+use crate::utils::make_scalar_function;
+use datafusion_common::{Result, arrow_datafusion_err};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+use datafusion_macros::user_doc;
+
+/// Computes `C(n, k)`, the number of ways to choose `k` items from `n`,
+/// via the multiplicative recurrence `C(n,0)=1`, `C(n,k)=C(n,k-1)*(n-k+1)/k`.
+/// Iterating `k' = min(k, n-k)` times keeps every intermediate product as
+/// small as possible, and each division is exact, so this stays correct far
+/// past the point where `n!/(k!(n-k)!)` would itself overflow i64.
+///
+/// The recurrence is carried out in `i128`: the mul-then-div order can push
+/// an *intermediate* product past `i64::MAX` even when the final `C(n,k)`
+/// fits comfortably. The loop stops as soon as a partial `C(n, i)` passes
+/// `i64::MAX`, and the final cast back to `i64` reports the overflow, so this
+/// only errors when the true value exceeds `i64::MAX`.
+///
+/// Returns `0` when `k < 0` or `k > n`, and `Ok(1)` when `k == 0`.
+pub(super) fn checked_binomial(n: i64, k: i64) -> Result<i64> {
+    if k < 0 || k > n {
+        return Ok(0);
+    }
+    let k = k.min(n - k);
+
+    let mut result: i128 = 1;
+    for i in 1..=k {
+        result = result * (n - i + 1) as i128 / i as i128;
+        // `C(n, i)` only grows for `i <= n/2`, so once it passes `i64::MAX`
+        // the final value will too; stop before `i128` itself can overflow.
+        if result > i64::MAX as i128 {
+            break;
+        }
+    }
+    i64::try_from(result).map_err(|_| {
+        arrow_datafusion_err!(ArrowError::ComputeError(format!(
+            "Overflow happened on BINOMIAL({n}, {k})"
+        )))
+    })
+}
+
+#[user_doc(
+    doc_section(label = "Math Functions"),
+    description = "Returns the number of ways to choose `k` items from `n` items without repetition and without order. Also known as `combinations`.",
+    syntax_example = "binomial(n, k)",
+    sql_example = r#"```sql
+> SELECT binomial(8, 2);
++----------------+
+| binomial(8,2)  |
++----------------+
+| 28             |
++----------------+
+```"#,
+    argument(name = "n", description = "Total number of items."),
+    argument(name = "k", description = "Number of items chosen.")
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct BinomialFunc {
+    signature: Signature,
+    aliases: Vec<String>,
+}
+
+impl Default for BinomialFunc {
+    fn default() -> Self {
+        BinomialFunc::new()
+    }
+}
+
+impl BinomialFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::uniform(2, vec![Int64], Volatility::Immutable),
+            aliases: vec![String::from("combinations")],
+        }
+    }
+}
+
+impl ScalarUDFImpl for BinomialFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "binomial"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(Int64)
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(binomial, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// `binomial`/`combinations` SQL function
+pub fn binomial(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let n = downcast_named_arg!((&args[0]), "n", Int64Array);
+    let k = downcast_named_arg!((&args[1]), "k", Int64Array);
+    Ok(Arc::new(
+        n.iter()
+            .zip(k.iter())
+            .map(|pair| match pair {
+                (Some(n), Some(k)) => checked_binomial(n, k).map(Some),
+                _ => Ok(None),
+            })
+            .collect::<Result<Int64Array>>()?,
+    ) as ArrayRef)
+}
+
+#[cfg(test)]
+mod test {
+    use datafusion_common::cast::as_int64_array;
+
+    use super::*;
+
+    #[test]
+    fn test_binomial() {
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Int64Array::from(vec![8, 5, 10, 10])), // n
+            Arc::new(Int64Array::from(vec![2, 0, -1, 11])), // k
+        ];
+
+        let result = binomial(&args).expect("failed to invoke binomial");
+        let ints = as_int64_array(&result).expect("failed to downcast binomial result");
+
+        let expected = Int64Array::from(vec![28, 1, 0, 0]);
+
+        assert_eq!(ints, &expected);
+    }
+
+    #[test]
+    fn test_binomial_no_false_overflow_on_large_intermediate_product() {
+        // C(62, 30) = 450,883,717,216,034,179, which fits comfortably under
+        // i64::MAX (~9.22e18), even though the naive mul-then-div recurrence
+        // run in i64 would overflow on an intermediate product.
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Int64Array::from(vec![62])),
+            Arc::new(Int64Array::from(vec![30])),
+        ];
+
+        let result = binomial(&args).expect("C(62, 30) should not overflow");
+        let ints = as_int64_array(&result).expect("failed to downcast binomial result");
+
+        let expected = Int64Array::from(vec![450_883_717_216_034_179]);
+
+        assert_eq!(ints, &expected);
+    }
+
+    #[test]
+    fn test_binomial_overflow() {
+        // C(1000, 500) is astronomically larger than i64::MAX.
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Int64Array::from(vec![1000])),
+            Arc::new(Int64Array::from(vec![500])),
+        ];
+
+        let err = binomial(&args).expect_err("expected overflow error");
+        assert!(err.to_string().contains("Overflow"));
+    }
+}
@@ -23,7 +23,9 @@ use std::any::Any;
 use std::sync::Arc;
 
 use arrow::datatypes::DataType;
-use arrow::datatypes::DataType::Int64;
+use arrow::datatypes::DataType::{
+    Int16, Int32, Int64, Int8, UInt16, UInt32, UInt64, UInt8,
+};
 
 use crate::utils::make_scalar_function;
 use datafusion_common::{Result, arrow_datafusion_err, exec_err};
@@ -35,7 +37,10 @@ use datafusion_macros::user_doc;
 
 /// Pre-computed factorial values for integers 0-20.
 /// factorial(n) for n > 20 overflows i64.
-/// This table is shared with the Spark factorial implementation.
+/// This table is shared with the Spark factorial implementation, with
+/// `gamma`/`lgamma`, which serve `Γ(n + 1)` exactly from it for small `n`
+/// instead of paying for Lanczos approximation error, and (via
+/// `factorial_scalar`) with `permutations`'s `k!` term.
 pub const FACTORIALS: [i64; 21] = [
     1,                    // 0!
     1,                    // 1!
@@ -88,7 +93,11 @@ impl Default for FactorialFunc {
 impl FactorialFunc {
     pub fn new() -> Self {
         Self {
-            signature: Signature::uniform(1, vec![Int64], Volatility::Immutable),
+            signature: Signature::uniform(
+                1,
+                vec![Int8, Int16, Int32, Int64, UInt8, UInt16, UInt32, UInt64],
+                Volatility::Immutable,
+            ),
         }
     }
 }
@@ -111,7 +120,14 @@ impl ScalarUDFImpl for FactorialFunc {
     }
 
     fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
-        make_scalar_function(factorial, vec![])(&args.args)
+        // Coerce any accepted integer width to Int64 before looking up the
+        // precomputed factorial table, so callers don't have to cast first.
+        let args = args
+            .args
+            .into_iter()
+            .map(|arg| arg.cast_to(&Int64, None))
+            .collect::<Result<Vec<_>>>()?;
+        make_scalar_function(factorial, vec![])(&args)
     }
 
     fn documentation(&self) -> Option<&Documentation> {
@@ -119,27 +135,31 @@ impl ScalarUDFImpl for FactorialFunc {
     }
 }
 
-/// Factorial SQL function
-/// 
-/// Uses the pre-computed `FACTORIALS` lookup table for O(1) performance.
-/// Behavior:
+/// Scalar factorial lookup, shared with `permutations` for its `k!` term.
+///
 /// - Values less than 2: returns 1
 /// - Values 2-20: returns factorial from lookup table
 /// - Values > 20: returns overflow error
+pub(crate) fn factorial_scalar(n: i64) -> Result<i64> {
+    match n {
+        n if n < 2 => Ok(1),
+        n if n <= 20 => Ok(FACTORIALS[n as usize]),
+        n => Err(arrow_datafusion_err!(ArrowError::ComputeError(format!(
+            "Overflow happened on FACTORIAL({n})"
+        )))),
+    }
+}
+
+/// Factorial SQL function
+///
+/// Uses the pre-computed `FACTORIALS` lookup table for O(1) performance.
 pub fn factorial(args: &[ArrayRef]) -> Result<ArrayRef> {
     match args[0].data_type() {
         Int64 => {
             let arg = downcast_named_arg!((&args[0]), "value", Int64Array);
             Ok(arg
                 .iter()
-                .map(|a| match a {
-                    Some(a) if a < 2 => Ok(Some(1i64)),
-                    Some(a) if a <= 20 => Ok(Some(FACTORIALS[a as usize])),
-                    Some(a) => Err(arrow_datafusion_err!(ArrowError::ComputeError(
-                        format!("Overflow happened on FACTORIAL({a})")
-                    ))),
-                    None => Ok(None),
-                })
+                .map(|a| a.map(factorial_scalar).transpose())
                 .collect::<Result<Int64Array>>()
                 .map(Arc::new)? as ArrayRef)
         }
@@ -150,6 +170,8 @@ pub fn factorial(args: &[ArrayRef]) -> Result<ArrayRef> {
 #[cfg(test)]
 mod test {
 
+    use arrow::array::Int32Array;
+    use arrow::datatypes::Field;
     use datafusion_common::cast::as_int64_array;
 
     use super::*;
@@ -168,4 +190,28 @@ mod test {
 
         assert_eq!(ints, &expected);
     }
+
+    #[test]
+    fn test_factorial_coerces_smaller_integer_types() {
+        let udf = FactorialFunc::new();
+        let arg = Arc::new(Int32Array::from(vec![0, 1, 2, 4])) as ArrayRef;
+        let args = ScalarFunctionArgs {
+            args: vec![ColumnarValue::Array(Arc::clone(&arg))],
+            arg_fields: vec![Field::new("a", DataType::Int32, true).into()],
+            number_rows: arg.len(),
+            return_field: Field::new("f", Int64, true).into(),
+        };
+
+        let result = udf
+            .invoke_with_args(args)
+            .expect("failed to invoke factorial with Int32 input")
+            .to_array(arg.len())
+            .expect("failed to materialize result array");
+        let ints =
+            as_int64_array(&result).expect("failed to initialize function factorial");
+
+        let expected = Int64Array::from(vec![1, 1, 2, 24]);
+
+        assert_eq!(ints, &expected);
+    }
 }
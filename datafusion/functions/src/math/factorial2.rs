@@ -0,0 +1,208 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow::{
+    array::{ArrayRef, Int64Array},
+    error::ArrowError,
+};
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::datatypes::DataType;
+use arrow::datatypes::DataType::{
+    Int16, Int32, Int64, Int8, UInt16, UInt32, UInt64, UInt8,
+};
+
+use crate::utils::make_scalar_function;
+use datafusion_common::{Result, arrow_datafusion_err, exec_err};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+use datafusion_macros::user_doc;
+
+/// Pre-computed double-factorial values for integers 0-33.
+/// factorial2(n) for n > 33 overflows i64.
+/// This table is `pub` so a Spark-compatible implementation can share it,
+/// exactly as with `FACTORIALS`.
+pub const DOUBLE_FACTORIALS: [i64; 34] = [
+    1,                     // 0!!
+    1,                     // 1!!
+    2,                     // 2!!
+    3,                     // 3!!
+    8,                     // 4!!
+    15,                    // 5!!
+    48,                    // 6!!
+    105,                   // 7!!
+    384,                   // 8!!
+    945,                   // 9!!
+    3840,                  // 10!!
+    10395,                 // 11!!
+    46080,                 // 12!!
+    135135,                // 13!!
+    645120,                // 14!!
+    2027025,               // 15!!
+    10321920,              // 16!!
+    34459425,              // 17!!
+    185794560,             // 18!!
+    654729075,             // 19!!
+    3715891200,            // 20!!
+    13749310575,           // 21!!
+    81749606400,           // 22!!
+    316234143225,          // 23!!
+    1961990553600,         // 24!!
+    7905853580625,         // 25!!
+    51011754393600,        // 26!!
+    213458046676875,       // 27!!
+    1428329123020800,      // 28!!
+    6190283353629375,      // 29!!
+    42849873690624000,     // 30!!
+    191898783962510625,    // 31!!
+    1371195958099968000,   // 32!!
+    6332659870762850625,   // 33!!
+];
+
+#[user_doc(
+    doc_section(label = "Math Functions"),
+    description = "Double factorial. Returns 1 if value is less than or equal to 0. Computes `n!! = n·(n−2)·(n−4)·…`.",
+    syntax_example = "factorial2(numeric_expression)",
+    sql_example = r#"```sql
+> SELECT factorial2(7);
++----------------+
+| factorial2(7)  |
++----------------+
+| 105            |
++----------------+
+```"#,
+    standard_argument(name = "numeric_expression", prefix = "Numeric")
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Factorial2Func {
+    signature: Signature,
+}
+
+impl Default for Factorial2Func {
+    fn default() -> Self {
+        Factorial2Func::new()
+    }
+}
+
+impl Factorial2Func {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::uniform(
+                1,
+                vec![Int8, Int16, Int32, Int64, UInt8, UInt16, UInt32, UInt64],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for Factorial2Func {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "factorial2"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(Int64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        // Coerce any accepted integer width to Int64 before looking up the
+        // precomputed double-factorial table, mirroring `FactorialFunc`.
+        let args = args
+            .args
+            .into_iter()
+            .map(|arg| arg.cast_to(&Int64, None))
+            .collect::<Result<Vec<_>>>()?;
+        make_scalar_function(factorial2, vec![])(&args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Factorial2 SQL function
+///
+/// Uses the pre-computed `DOUBLE_FACTORIALS` lookup table for O(1)
+/// performance.
+/// Behavior:
+/// - Values less than or equal to 0: returns 1
+/// - Values 1-33: returns double factorial from lookup table
+/// - Values > 33: returns overflow error
+pub fn factorial2(args: &[ArrayRef]) -> Result<ArrayRef> {
+    match args[0].data_type() {
+        Int64 => {
+            let arg = downcast_named_arg!((&args[0]), "value", Int64Array);
+            Ok(arg
+                .iter()
+                .map(|a| match a {
+                    Some(a) if a <= 0 => Ok(Some(1i64)),
+                    Some(a) if a <= 33 => Ok(Some(DOUBLE_FACTORIALS[a as usize])),
+                    Some(a) => Err(arrow_datafusion_err!(ArrowError::ComputeError(
+                        format!("Overflow happened on FACTORIAL2({a})")
+                    ))),
+                    None => Ok(None),
+                })
+                .collect::<Result<Int64Array>>()
+                .map(Arc::new)? as ArrayRef)
+        }
+        other => exec_err!("Unsupported data type {other:?} for function factorial2."),
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use datafusion_common::cast::as_int64_array;
+
+    use super::*;
+
+    #[test]
+    fn test_factorial2_i64() {
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Int64Array::from(vec![-1, 0, 1, 5, 8])), // input
+        ];
+
+        let result =
+            factorial2(&args).expect("failed to initialize function factorial2");
+        let ints =
+            as_int64_array(&result).expect("failed to initialize function factorial2");
+
+        let expected = Int64Array::from(vec![1, 1, 1, 15, 384]);
+
+        assert_eq!(ints, &expected);
+    }
+
+    #[test]
+    fn test_factorial2_overflow() {
+        let args: Vec<ArrayRef> = vec![Arc::new(Int64Array::from(vec![34]))];
+
+        let err = factorial2(&args).expect_err("expected overflow error");
+        assert!(err.to_string().contains("Overflow"));
+    }
+}
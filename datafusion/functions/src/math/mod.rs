@@ -0,0 +1,75 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! "math" DataFusion functions
+
+use std::sync::Arc;
+
+use datafusion_expr::ScalarUDF;
+
+pub mod binomial;
+pub mod factorial;
+pub mod factorial2;
+pub mod gamma;
+pub mod lgamma;
+pub mod permutations;
+
+make_udf_function!(factorial::FactorialFunc, factorial);
+make_udf_function!(factorial2::Factorial2Func, factorial2);
+make_udf_function!(gamma::GammaFunc, gamma);
+make_udf_function!(lgamma::LgammaFunc, lgamma);
+make_udf_function!(binomial::BinomialFunc, binomial);
+make_udf_function!(permutations::PermutationsFunc, permutations);
+
+pub mod expr_fn {
+    export_functions!(
+        (factorial, "Returns the factorial of a number", value),
+        (
+            factorial2,
+            "Returns the double factorial (n!!) of a number",
+            value
+        ),
+        (gamma, "Returns the gamma function of a number", value),
+        (
+            lgamma,
+            "Returns the natural logarithm of the absolute value of the gamma function of a number",
+            value
+        ),
+        (
+            binomial,
+            "Returns the number of ways to choose k items from n items",
+            n k
+        ),
+        (
+            permutations,
+            "Returns the number of ways to arrange k items chosen from n items",
+            n k
+        )
+    );
+}
+
+/// Returns all DataFusion functions defined in this package
+pub fn functions() -> Vec<Arc<ScalarUDF>> {
+    vec![
+        factorial(),
+        factorial2(),
+        gamma(),
+        lgamma(),
+        binomial(),
+        permutations(),
+    ]
+}
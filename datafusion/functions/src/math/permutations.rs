@@ -0,0 +1,156 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, Int64Array};
+use arrow::datatypes::DataType;
+use arrow::datatypes::DataType::Int64;
+use arrow::error::ArrowError;
+
+use crate::math::binomial::checked_binomial;
+use crate::math::factorial::factorial_scalar;
+use crate::utils::make_scalar_function;
+use datafusion_common::{Result, arrow_datafusion_err};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+use datafusion_macros::user_doc;
+
+/// Computes `P(n, k) = C(n, k) * k!`, the number of ordered arrangements of
+/// `k` items drawn from `n`, reusing `binomial`'s overflow-checked
+/// recurrence and `factorial`'s table-backed `k!`.
+fn checked_permutations(n: i64, k: i64) -> Result<i64> {
+    let combinations = checked_binomial(n, k)?;
+    let k_factorial = factorial_scalar(k)?;
+    combinations.checked_mul(k_factorial).ok_or_else(|| {
+        arrow_datafusion_err!(ArrowError::ComputeError(format!(
+            "Overflow happened on PERMUTATIONS({n}, {k})"
+        )))
+    })
+}
+
+#[user_doc(
+    doc_section(label = "Math Functions"),
+    description = "Returns the number of ways to arrange `k` items chosen from `n` items, where order matters.",
+    syntax_example = "permutations(n, k)",
+    sql_example = r#"```sql
+> SELECT permutations(5, 2);
++--------------------+
+| permutations(5,2)  |
++--------------------+
+| 20                 |
++--------------------+
+```"#,
+    argument(name = "n", description = "Total number of items."),
+    argument(name = "k", description = "Number of items chosen.")
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct PermutationsFunc {
+    signature: Signature,
+}
+
+impl Default for PermutationsFunc {
+    fn default() -> Self {
+        PermutationsFunc::new()
+    }
+}
+
+impl PermutationsFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::uniform(2, vec![Int64], Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for PermutationsFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "permutations"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(Int64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(permutations, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// `permutations` SQL function
+pub fn permutations(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let n = downcast_named_arg!((&args[0]), "n", Int64Array);
+    let k = downcast_named_arg!((&args[1]), "k", Int64Array);
+    Ok(Arc::new(
+        n.iter()
+            .zip(k.iter())
+            .map(|pair| match pair {
+                (Some(n), Some(k)) => checked_permutations(n, k).map(Some),
+                _ => Ok(None),
+            })
+            .collect::<Result<Int64Array>>()?,
+    ) as ArrayRef)
+}
+
+#[cfg(test)]
+mod test {
+    use datafusion_common::cast::as_int64_array;
+
+    use super::*;
+
+    #[test]
+    fn test_permutations() {
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Int64Array::from(vec![5, 5, 10])), // n
+            Arc::new(Int64Array::from(vec![2, 0, -1])), // k
+        ];
+
+        let result = permutations(&args).expect("failed to invoke permutations");
+        let ints =
+            as_int64_array(&result).expect("failed to downcast permutations result");
+
+        let expected = Int64Array::from(vec![20, 1, 0]);
+
+        assert_eq!(ints, &expected);
+    }
+
+    #[test]
+    fn test_permutations_overflow() {
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Int64Array::from(vec![25])),
+            Arc::new(Int64Array::from(vec![25])),
+        ];
+
+        let err = permutations(&args).expect_err("expected overflow error");
+        assert!(err.to_string().contains("Overflow"));
+    }
+}